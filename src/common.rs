@@ -1,4 +1,25 @@
 use core::str;
+use std::time::{Duration, SystemTime};
+
+use windows_sys::Win32::Foundation::FILETIME;
+
+/// Converts a Windows `FILETIME` (100ns intervals since 1601-01-01) into a
+/// `SystemTime`.
+pub(crate) fn filetime_to_system_time(ft: FILETIME) -> SystemTime {
+    const EPOCH_DIFFERENCE_100NS: u64 = 116_444_736_000_000_000;
+
+    let ticks = ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64;
+    let unix_100ns = ticks.saturating_sub(EPOCH_DIFFERENCE_100NS);
+
+    SystemTime::UNIX_EPOCH + Duration::from_nanos(unix_100ns * 100)
+}
+
+/// Converts a Windows `FILETIME` used as a duration (100ns intervals) into a
+/// `Duration`.
+pub(crate) fn filetime_to_duration(ft: FILETIME) -> Duration {
+    let ticks = ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64;
+    Duration::from_nanos(ticks * 100)
+}
 
 pub(crate) fn get_string_utf8(buf: &[u8]) -> Option<String> {
     let null_terminator = buf.iter().position(|val| val.eq(&0)).unwrap_or(buf.len());
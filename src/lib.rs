@@ -1,8 +1,16 @@
 #![feature(str_from_raw_parts)]
 mod common;
 mod process;
+pub mod process_environment;
+pub mod process_identity;
+pub mod process_inject;
 pub mod process_memory;
+pub mod process_memory_batch;
+pub mod process_memory_regions;
 pub mod process_memory_write;
+pub mod process_metrics;
 pub mod process_module;
+pub mod process_owner;
+pub mod process_scan;
 
 pub use process::Process;
@@ -4,24 +4,44 @@ use std::{
     mem::{size_of, MaybeUninit},
 };
 
+use std::time::{Duration, SystemTime};
+
+// `NtQueryInformationProcess` and `PROCESS_BASIC_INFORMATION` are
+// NT-native APIs, not Win32 ones: windows-sys exposes them under `Wdk`,
+// behind the `Wdk_System_Threading` feature.
+use windows_sys::Wdk::System::Threading::{NtQueryInformationProcess, PROCESS_BASIC_INFORMATION};
 use windows_sys::Win32::{
     Foundation::{CloseHandle, BOOL, HANDLE, STILL_ACTIVE, WAIT_FAILED, WAIT_TIMEOUT},
     System::{
+        Memory::{VirtualQueryEx, MEMORY_BASIC_INFORMATION},
         ProcessStatus::{K32EnumProcesses, K32GetModuleBaseNameW},
         Threading::{
-            GetExitCodeProcess, IsWow64Process, OpenProcess, WaitForSingleObject,
-            PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE,
+            GetExitCodeProcess, IsWow64Process, OpenProcess, TerminateProcess,
+            WaitForSingleObject, INFINITE, PROCESS_CREATE_THREAD, PROCESS_QUERY_INFORMATION,
+            PROCESS_TERMINATE, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE,
         },
     },
 };
 
 use crate::common::get_string_utf16;
+use crate::process_memory::Address;
+
+/// Access rights requested on every handle this crate opens. Grown as new
+/// functionality is added that needs a broader access mask.
+const ACCESS_TYPE: u32 = PROCESS_QUERY_INFORMATION
+    | PROCESS_VM_READ
+    | PROCESS_VM_WRITE
+    | PROCESS_VM_OPERATION
+    | PROCESS_TERMINATE
+    | PROCESS_CREATE_THREAD;
 
 /// A Process instance running on the system
 pub struct Process {
     pub(crate) handle: HANDLE,
+    pid: u32,
     name: RefCell<Option<String>>,
     is_64_bit: Cell<Option<bool>>,
+    start_time: Cell<Option<SystemTime>>,
 }
 
 impl Drop for Process {
@@ -40,52 +60,80 @@ impl Drop for Process {
 }
 
 impl Process {
-    /// Enumerates processes open in the current system.
-    /// For performance reasons, the returned iterator is limited to a maximum size of 1024.
+    /// Enumerates processes open in the current system. Unlike earlier
+    /// versions, this is no longer capped at a fixed number of processes: a
+    /// heap buffer is grown and the call retried until `K32EnumProcesses`
+    /// returns fewer PIDs than the buffer could hold.
     ///
     /// Documentation: https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-enumprocesses
     pub fn get_processes() -> impl DoubleEndedIterator<Item = Process> {
-        const ACCESS_TYPE: u32 =
-            PROCESS_QUERY_INFORMATION | PROCESS_VM_READ | PROCESS_VM_WRITE | PROCESS_VM_OPERATION;
+        const INITIAL_CAPACITY: usize = 1024;
 
-        const MAX_PROCESSES: usize = 1024;
+        let mut capacity = INITIAL_CAPACITY;
+        let pids = loop {
+            let mut pid_process = vec![0u32; capacity];
+            let mut lpcneeded = 0u32;
 
-        unsafe {
-            // pid_process will be an array receiving the list of process identifiers
-            let mut pid_process =
-                MaybeUninit::<[MaybeUninit<u32>; MAX_PROCESSES]>::uninit().assume_init();
-
-            // The number of bytes returned in the array defined in pid_process
-            let mut lpcneeded = MaybeUninit::<u32>::uninit();
-
-            let success = K32EnumProcesses(
-                pid_process.as_mut_ptr() as *mut u32,
-                pid_process.len() as _,
-                &mut lpcneeded as *mut _ as *mut u32,
-            );
-
-            let no_of_processes = if success != 0 {
-                lpcneeded.assume_init().wrapping_div(size_of::<u32>() as _)
-            } else {
-                0
+            let success = unsafe {
+                K32EnumProcesses(
+                    pid_process.as_mut_ptr(),
+                    (pid_process.len() * size_of::<u32>()) as u32,
+                    &mut lpcneeded,
+                )
             };
 
-            (0..no_of_processes as usize).filter_map(move |i| {
-                let pid = core::mem::transmute(pid_process[i]);
-                let handle = OpenProcess(ACCESS_TYPE, 0, pid);
+            if success == 0 {
+                break Vec::new();
+            }
 
-                match handle {
-                    0 => None,
-                    _ => Some(Process {
-                        handle,
-                        name: RefCell::new(None),
-                        is_64_bit: Cell::new(None),
-                    }),
-                }
-            })
+            let returned = lpcneeded as usize / size_of::<u32>();
+            if returned < pid_process.len() {
+                pid_process.truncate(returned);
+                break pid_process;
+            }
+
+            // The buffer was filled exactly: processes may have been
+            // truncated, so grow and retry.
+            capacity *= 2;
+        };
+
+        pids.into_iter().filter_map(Self::open)
+    }
+
+    /// Opens the process with the given PID directly, instead of scanning
+    /// the full process list. Returns `None` if the PID does not exist or
+    /// cannot be opened with the access this crate requests.
+    pub fn by_pid(pid: u32) -> Option<Process> {
+        Self::open(pid)
+    }
+
+    fn open(pid: u32) -> Option<Process> {
+        let handle = unsafe { OpenProcess(ACCESS_TYPE, 0, pid) };
+
+        match handle {
+            0 => None,
+            _ => Some(Process {
+                handle,
+                pid,
+                name: RefCell::new(None),
+                is_64_bit: Cell::new(None),
+                start_time: Cell::new(None),
+            }),
         }
     }
 
+    /// Returns the PID this `Process` was opened with.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Returns the PID of the process that created this one. Windows does
+    /// not keep this value up to date after creation, so the returned PID
+    /// may belong to a process that has since exited or been reused.
+    pub fn parent_pid(&self) -> Option<u32> {
+        Some(self.basic_information()?.InheritedFromUniqueProcessId as u32)
+    }
+
     /// Returns the name of the process
     pub fn get_name(&self) -> Option<String> {
         let mut name = self.name.borrow_mut();
@@ -162,6 +210,94 @@ impl Process {
         }
     }
 
+    /// Queries `PROCESS_BASIC_INFORMATION` via `NtQueryInformationProcess`.
+    /// Shared by the modules that need the PEB base address or the
+    /// inherited-from PID.
+    pub(crate) fn basic_information(&self) -> Option<PROCESS_BASIC_INFORMATION> {
+        let mut info = MaybeUninit::<PROCESS_BASIC_INFORMATION>::uninit();
+        let mut return_length = 0u32;
+
+        let status = unsafe {
+            NtQueryInformationProcess(
+                self.handle,
+                0, // ProcessBasicInformation
+                info.as_mut_ptr() as *mut _,
+                size_of::<PROCESS_BASIC_INFORMATION>() as u32,
+                &mut return_length,
+            )
+        };
+
+        match status {
+            0 => Some(unsafe { info.assume_init() }),
+            _ => None,
+        }
+    }
+
+    /// Queries `ProcessWow64Information` via `NtQueryInformationProcess` to
+    /// obtain the base address of the 32-bit PEB for a WOW64 process.
+    /// `basic_information`'s `PebBaseAddress` is the *native* (64-bit) PEB
+    /// even for a WOW64 target, so this is the only way to reach the
+    /// `PEB32`/`RTL_USER_PROCESS_PARAMETERS32` layout. Returns `None` if the
+    /// query fails or if the process is not actually running under WOW64.
+    pub(crate) fn wow64_peb_address(&self) -> Option<Address> {
+        let mut peb32 = MaybeUninit::<usize>::uninit();
+        let mut return_length = 0u32;
+
+        let status = unsafe {
+            NtQueryInformationProcess(
+                self.handle,
+                26, // ProcessWow64Information
+                peb32.as_mut_ptr() as *mut _,
+                size_of::<usize>() as u32,
+                &mut return_length,
+            )
+        };
+
+        match status {
+            0 => match unsafe { peb32.assume_init() } {
+                0 => None,
+                address => Some(address as Address),
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the process' creation time if it's already been fetched and
+    /// cached by a prior call to [`Process::get_start_time`] or
+    /// [`Process::get_cpu_times`](crate::process_metrics). Creation time
+    /// can't change for the life of a `Process`, so once read it's safe to
+    /// reuse indefinitely.
+    pub(crate) fn cached_start_time(&self) -> Option<SystemTime> {
+        self.start_time.get()
+    }
+
+    pub(crate) fn set_cached_start_time(&self, value: SystemTime) {
+        self.start_time.set(Some(value));
+    }
+
+    /// Queries `VirtualQueryEx` for the committed/reserved region containing
+    /// `address` and returns how many bytes remain in it from `address`
+    /// onward. Used to size a single read instead of guessing a fixed
+    /// buffer that may run past the end of a small allocation.
+    pub(crate) fn region_len_from(&self, address: Address) -> Option<usize> {
+        let mut info = MaybeUninit::<MEMORY_BASIC_INFORMATION>::uninit();
+        let written = unsafe {
+            VirtualQueryEx(
+                self.handle,
+                address as _,
+                info.as_mut_ptr(),
+                size_of::<MEMORY_BASIC_INFORMATION>(),
+            )
+        };
+        if written == 0 {
+            return None;
+        }
+
+        let info = unsafe { info.assume_init() };
+        let region_end = (info.BaseAddress as Address).wrapping_add(info.RegionSize as Address);
+        (region_end - address).try_into().ok()
+    }
+
     /// Checks if the process is currently running
     pub fn is_open(&self) -> Option<bool> {
         unsafe {
@@ -181,4 +317,28 @@ impl Process {
             }
         }
     }
+
+    /// Terminates the process with the given exit code, via
+    /// `TerminateProcess`. Returns `true` on success.
+    pub fn kill(&self, exit_code: u32) -> bool {
+        unsafe { TerminateProcess(self.handle, exit_code) != 0 }
+    }
+
+    /// Blocks until the process exits, or until `timeout` elapses (waits
+    /// indefinitely if `None`). Returns the process' exit code, or `None` on
+    /// timeout or if the wait itself failed.
+    pub fn wait_for_exit(&self, timeout: Option<Duration>) -> Option<i32> {
+        let timeout_ms = timeout.map_or(INFINITE, |duration| duration.as_millis() as u32);
+
+        match unsafe { WaitForSingleObject(self.handle, timeout_ms) } {
+            WAIT_FAILED | WAIT_TIMEOUT => None,
+            _ => {
+                let mut exit_code = MaybeUninit::<u32>::uninit();
+                match unsafe { GetExitCodeProcess(self.handle, exit_code.as_mut_ptr()) } {
+                    0 => None,
+                    _ => Some(unsafe { exit_code.assume_init() } as i32),
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,164 @@
+use windows_sys::Win32::Foundation::LocalFree;
+use windows_sys::Win32::UI::Shell::CommandLineToArgvW;
+
+use crate::common::get_string_utf16;
+use crate::process::Process;
+use crate::process_memory::Address;
+
+const PEB_OFFSET_PROCESS_PARAMETERS_64: isize = 0x20;
+const PEB_OFFSET_PROCESS_PARAMETERS_32: isize = 0x10;
+
+const PARAMS_OFFSET_CURRENT_DIRECTORY_64: isize = 0x38;
+const PARAMS_OFFSET_COMMAND_LINE_64: isize = 0x70;
+const PARAMS_OFFSET_ENVIRONMENT_64: isize = 0x80;
+
+const PARAMS_OFFSET_CURRENT_DIRECTORY_32: isize = 0x24;
+const PARAMS_OFFSET_COMMAND_LINE_32: isize = 0x40;
+const PARAMS_OFFSET_ENVIRONMENT_32: isize = 0x48;
+
+/// The environment block is read in one shot, capped at this many UTF-16
+/// code units.
+const MAX_ENVIRONMENT_LEN: usize = 32 * 1024;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct UnicodeString64 {
+    length: u16,
+    maximum_length: u16,
+    _padding: u32,
+    buffer: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct UnicodeString32 {
+    length: u16,
+    maximum_length: u16,
+    buffer: u32,
+}
+
+impl Process {
+    /// Recovers the process' command line as it was passed to
+    /// `CreateProcess`, split into individual arguments the same way the
+    /// shell would via `CommandLineToArgvW`.
+    pub fn get_command_line(&self) -> Option<Vec<String>> {
+        let line = self.command_line_string()?;
+        split_command_line(&line)
+    }
+
+    /// Recovers the process' raw, unsplit command line string from the
+    /// PEB's `ProcessParameters`.
+    fn command_line_string(&self) -> Option<String> {
+        self.read_process_parameters_string(PARAMS_OFFSET_COMMAND_LINE_64, PARAMS_OFFSET_COMMAND_LINE_32)
+    }
+
+    /// Recovers the process' current working directory from the PEB.
+    pub fn get_current_directory(&self) -> Option<String> {
+        self.read_process_parameters_string(
+            PARAMS_OFFSET_CURRENT_DIRECTORY_64,
+            PARAMS_OFFSET_CURRENT_DIRECTORY_32,
+        )
+    }
+
+    /// Recovers the process' environment block from the PEB, as a list of
+    /// `KEY=VALUE` entries.
+    pub fn get_environment(&self) -> Option<Vec<String>> {
+        let params = self.process_parameters_address()?;
+        let environment_offset = match self.is_64_bit()? {
+            true => PARAMS_OFFSET_ENVIRONMENT_64,
+            false => PARAMS_OFFSET_ENVIRONMENT_32,
+        };
+        let environment = self.read_pointer(params + environment_offset)?;
+
+        // `ReadProcessMemory` fails wholesale the moment any part of the
+        // range crosses into unmapped memory, and the environment block is
+        // typically far smaller than `MAX_ENVIRONMENT_LEN`, so read only as
+        // many bytes as are actually committed from `environment` onward.
+        let available_bytes = self.region_len_from(environment)?;
+        let len = (available_bytes / 2).min(MAX_ENVIRONMENT_LEN);
+
+        // The environment block is a double-null-terminated list of
+        // null-terminated wide strings.
+        let mut buf = vec![0u16; len];
+        if !self.read_buf(environment, &mut buf) {
+            return None;
+        }
+
+        Some(
+            buf.split(|&unit| unit == 0)
+                .take_while(|entry| !entry.is_empty())
+                .filter_map(|entry| String::from_utf16(entry).ok())
+                .collect(),
+        )
+    }
+
+    fn process_parameters_address(&self) -> Option<Address> {
+        match self.is_64_bit()? {
+            true => {
+                let peb = self.basic_information()?.PebBaseAddress as Address;
+                self.read_pointer(peb + PEB_OFFSET_PROCESS_PARAMETERS_64)
+            }
+            // `basic_information`'s PebBaseAddress is the native 64-bit PEB
+            // even for a WOW64 target: the 32-bit PEB lives elsewhere and
+            // must be located via ProcessWow64Information.
+            false => {
+                let peb32 = self.wow64_peb_address()?;
+                self.read_pointer(peb32 + PEB_OFFSET_PROCESS_PARAMETERS_32)
+            }
+        }
+    }
+
+    fn read_process_parameters_string(&self, offset_64: isize, offset_32: isize) -> Option<String> {
+        let params = self.process_parameters_address()?;
+
+        match self.is_64_bit()? {
+            true => {
+                let raw = self.read_value::<UnicodeString64>(params + offset_64)?;
+                self.read_unicode_string(raw.buffer as Address, raw.length)
+            }
+            false => {
+                let raw = self.read_value::<UnicodeString32>(params + offset_32)?;
+                self.read_unicode_string(raw.buffer as Address, raw.length)
+            }
+        }
+    }
+
+    fn read_unicode_string(&self, buffer: Address, length_bytes: u16) -> Option<String> {
+        if length_bytes == 0 {
+            return Some(String::new());
+        }
+
+        let mut buf = vec![0u16; length_bytes as usize / 2];
+        if !self.read_buf(buffer, &mut buf) {
+            return None;
+        }
+
+        get_string_utf16(&buf)
+    }
+}
+
+/// Splits a raw command line string into arguments using `CommandLineToArgvW`.
+fn split_command_line(line: &str) -> Option<Vec<String>> {
+    let wide: Vec<u16> = line.encode_utf16().chain(std::iter::once(0)).collect();
+    let mut argc = 0i32;
+
+    let argv = unsafe { CommandLineToArgvW(wide.as_ptr(), &mut argc) };
+    if argv.is_null() {
+        return None;
+    }
+
+    let args = (0..argc as isize)
+        .map(|i| {
+            let arg = unsafe { *argv.offset(i) };
+            let len = unsafe { (0..).take_while(|&j| *arg.offset(j) != 0).count() };
+            let slice = unsafe { std::slice::from_raw_parts(arg, len) };
+            String::from_utf16_lossy(slice)
+        })
+        .collect();
+
+    unsafe {
+        LocalFree(argv as _);
+    }
+
+    Some(args)
+}
@@ -0,0 +1,63 @@
+use std::mem::MaybeUninit;
+use std::time::SystemTime;
+
+use windows_sys::Win32::Foundation::FILETIME;
+use windows_sys::Win32::System::ProcessStatus::K32GetModuleFileNameExW;
+use windows_sys::Win32::System::Threading::GetProcessTimes;
+
+use crate::common::{filetime_to_system_time, get_string_utf16};
+use crate::process::Process;
+
+impl Process {
+    /// Recovers the full path to the process' executable image via
+    /// `K32GetModuleFileNameExW`.
+    pub fn get_exe_path(&self) -> Option<String> {
+        let mut buf = [0u16; 1024];
+        let len = unsafe { K32GetModuleFileNameExW(self.handle, 0, buf.as_mut_ptr(), buf.len() as u32) };
+
+        match len {
+            0 => None,
+            _ => get_string_utf16(&buf[..len as usize]),
+        }
+    }
+
+    /// Returns the PID of the process that created this one. Alias of
+    /// [`Process::parent_pid`] kept for naming symmetry with the other
+    /// `get_*` metadata accessors in this module.
+    pub fn get_parent_pid(&self) -> Option<u32> {
+        self.parent_pid()
+    }
+
+    /// Returns the process' creation time as a Unix-epoch timestamp. Cached
+    /// after the first successful call, since a process' creation time
+    /// can't change.
+    pub fn get_start_time(&self) -> Option<SystemTime> {
+        if let Some(start_time) = self.cached_start_time() {
+            return Some(start_time);
+        }
+
+        let mut creation = MaybeUninit::<FILETIME>::uninit();
+        let mut exit = MaybeUninit::<FILETIME>::uninit();
+        let mut kernel = MaybeUninit::<FILETIME>::uninit();
+        let mut user = MaybeUninit::<FILETIME>::uninit();
+
+        let success = unsafe {
+            GetProcessTimes(
+                self.handle,
+                creation.as_mut_ptr(),
+                exit.as_mut_ptr(),
+                kernel.as_mut_ptr(),
+                user.as_mut_ptr(),
+            )
+        };
+
+        if success == 0 {
+            return None;
+        }
+
+        let creation = unsafe { creation.assume_init() };
+        let start_time = filetime_to_system_time(creation);
+        self.set_cached_start_time(start_time);
+        Some(start_time)
+    }
+}
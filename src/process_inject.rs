@@ -0,0 +1,210 @@
+use std::ffi::CString;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::iter::once;
+use std::mem::{transmute, MaybeUninit};
+use std::os::windows::ffi::OsStrExt;
+use std::path::Path;
+
+use windows_sys::Win32::Foundation::CloseHandle;
+use windows_sys::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
+use windows_sys::Win32::System::Threading::{
+    CreateRemoteThread, GetExitCodeThread, WaitForSingleObject, INFINITE,
+};
+
+use crate::process::Process;
+use crate::process_memory::Address;
+use crate::process_module::ProcessModule;
+
+impl Process {
+    /// Injects a DLL into the process via the classic remote
+    /// `LoadLibraryW` technique: writes the DLL path into memory allocated
+    /// in the target, then starts a remote thread at `LoadLibraryW` with
+    /// that address as its argument. Returns the loaded
+    /// [`ProcessModule`] on success.
+    pub fn inject_dll(&self, path: &Path) -> Result<ProcessModule, InjectError> {
+        let dll_is_64_bit = dll_is_64_bit(path).ok_or(InjectError::InvalidDll)?;
+        if self.is_64_bit() != Some(dll_is_64_bit) {
+            return Err(InjectError::BitnessMismatch);
+        }
+
+        let load_library_w =
+            remote_proc_address(self, "LoadLibraryW").ok_or(InjectError::ResolveFailed)?;
+
+        let wide_path: Vec<u16> = path
+            .as_os_str()
+            .encode_wide()
+            .chain(once(0))
+            .collect();
+        let size = std::mem::size_of_val(wide_path.as_slice());
+
+        let remote_buf = self
+            .allocate_memory(size)
+            .ok_or(InjectError::AllocationFailed)?;
+        if !self.write_buf(remote_buf, &wide_path) {
+            self.free_memory(remote_buf);
+            return Err(InjectError::WriteFailed);
+        }
+
+        let result = run_remote_thread(self, load_library_w, remote_buf as usize);
+        self.free_memory(remote_buf);
+
+        match result {
+            Some(exit_code) if exit_code != 0 => {}
+            _ => return Err(InjectError::LoadLibraryFailed),
+        }
+
+        // `GetExitCodeThread` truncates `LoadLibraryW`'s return value to a
+        // 32-bit DWORD, so the module base it carries can't be trusted to
+        // match on a 64-bit target. Re-resolve the module instead, by the
+        // file name of the path we just asked it to load (compared
+        // case-insensitively; the directory is not compared).
+        let injected_file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or(InjectError::ModuleNotFound)?;
+
+        self.modules()
+            .find(|module| {
+                module.get_file_name(self).is_some_and(|file_name| {
+                    Path::new(&file_name)
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.eq_ignore_ascii_case(injected_file_name))
+                })
+            })
+            .ok_or(InjectError::ModuleNotFound)
+    }
+
+    /// Unloads a module previously injected with [`Process::inject_dll`], by
+    /// starting a remote thread at `FreeLibrary` with the module's base
+    /// address as its argument.
+    pub fn eject_dll(&self, module: &ProcessModule) -> bool {
+        let Some(free_library) = remote_proc_address(self, "FreeLibrary") else {
+            return false;
+        };
+        let Some(base) = module.get_base_address(self) else {
+            return false;
+        };
+
+        run_remote_thread(self, free_library, base as usize).is_some_and(|exit_code| exit_code != 0)
+    }
+}
+
+/// Starts a remote thread at `start_address` with `argument`, waits for it
+/// to finish and returns its exit code.
+fn run_remote_thread(process: &Process, start_address: Address, argument: usize) -> Option<u32> {
+    let thread = unsafe {
+        CreateRemoteThread(
+            process.handle,
+            std::ptr::null(),
+            0,
+            Some(transmute::<Address, unsafe extern "system" fn(*mut core::ffi::c_void) -> u32>(
+                start_address,
+            )),
+            argument as *mut _,
+            0,
+            std::ptr::null_mut(),
+        )
+    };
+
+    if thread == 0 {
+        return None;
+    }
+
+    unsafe {
+        WaitForSingleObject(thread, INFINITE);
+    }
+
+    let mut exit_code = MaybeUninit::<u32>::uninit();
+    let success = unsafe { GetExitCodeThread(thread, exit_code.as_mut_ptr()) };
+    unsafe {
+        CloseHandle(thread);
+    }
+
+    match success {
+        0 => None,
+        _ => Some(unsafe { exit_code.assume_init() }),
+    }
+}
+
+/// Resolves the address of `proc_name` inside `kernel32.dll` as mapped in
+/// `process`, by finding its offset in the current process' own
+/// `kernel32.dll` (mapped at the same base across processes of the same
+/// bitness) and applying that offset to the target's module base.
+fn remote_proc_address(process: &Process, proc_name: &str) -> Option<Address> {
+    let kernel32_name: Vec<u16> = "kernel32.dll".encode_utf16().chain(once(0)).collect();
+    let local_kernel32 = unsafe { GetModuleHandleW(kernel32_name.as_ptr()) };
+    if local_kernel32 == 0 {
+        return None;
+    }
+
+    let proc_name_c = CString::new(proc_name).ok()?;
+    let local_proc = unsafe { GetProcAddress(local_kernel32, proc_name_c.as_ptr() as *const u8) }?;
+    let offset = local_proc as isize - local_kernel32 as isize;
+
+    let target_kernel32 = process.modules().find(|module| {
+        module
+            .get_name(process)
+            .is_some_and(|name| name.eq_ignore_ascii_case("kernel32.dll"))
+    })?;
+    let target_base = target_kernel32.get_base_address(process)?;
+
+    Some(target_base + offset)
+}
+
+const IMAGE_FILE_MACHINE_I386: u16 = 0x014c;
+const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+
+/// Reads the `IMAGE_FILE_HEADER.Machine` field out of the DLL's PE header to
+/// determine whether it's a 32-bit or 64-bit image, without mapping the
+/// whole file. Returns `None` if the file can't be read or isn't a
+/// recognizable PE for either architecture.
+fn dll_is_64_bit(path: &Path) -> Option<bool> {
+    let mut file = File::open(path).ok()?;
+
+    let mut dos_header = [0u8; 0x40];
+    file.read_exact(&mut dos_header).ok()?;
+    if &dos_header[0..2] != b"MZ" {
+        return None;
+    }
+    let e_lfanew = u32::from_le_bytes(dos_header[0x3c..0x40].try_into().ok()?);
+
+    file.seek(SeekFrom::Start(e_lfanew as u64)).ok()?;
+    let mut pe_header = [0u8; 6];
+    file.read_exact(&mut pe_header).ok()?;
+    if &pe_header[0..4] != b"PE\0\0" {
+        return None;
+    }
+    let machine = u16::from_le_bytes(pe_header[4..6].try_into().ok()?);
+
+    match machine {
+        IMAGE_FILE_MACHINE_AMD64 => Some(true),
+        IMAGE_FILE_MACHINE_I386 => Some(false),
+        _ => None,
+    }
+}
+
+/// Errors that can occur while injecting a DLL with
+/// [`Process::inject_dll`]. [`Process::eject_dll`] reports failure as a
+/// plain `false` instead.
+#[derive(Debug)]
+pub enum InjectError {
+    /// `path` could not be read, or isn't a recognizable 32-bit or 64-bit PE
+    /// image.
+    InvalidDll,
+    /// The DLL's bitness does not match the target process' (e.g. injecting
+    /// a 64-bit DLL into a WOW64 target).
+    BitnessMismatch,
+    /// Failed to resolve `LoadLibraryW` in the target.
+    ResolveFailed,
+    /// `VirtualAllocEx` failed to reserve memory in the target.
+    AllocationFailed,
+    /// Failed to write the DLL path into the target.
+    WriteFailed,
+    /// The remote thread could not be started or its exit code could not be
+    /// read, or `LoadLibraryW` returned a null module handle.
+    LoadLibraryFailed,
+    /// The module loaded by the remote thread could not be found afterward.
+    ModuleNotFound,
+}
@@ -75,8 +75,12 @@ impl Process {
         }
     }
 
-    /// Resolves a pointer path, returning the memory address at the end of the path
-    pub fn deref_offsets(&self, address: Address, offsets: &[u32]) -> Option<Address> {
+    /// Resolves a pointer path, returning the memory address at the end of
+    /// the path. Offsets may be negative to walk a structure backward, and
+    /// are applied as full-width `isize` displacements. The last offset is
+    /// added but not dereferenced, matching the usual "base + path" pointer
+    /// chain convention.
+    pub fn deref_offsets(&self, address: Address, offsets: &[i64]) -> Option<Address> {
         let mut address = self.read_pointer(address)?;
 
         if let Some((&last, path)) = offsets.split_last() {
@@ -90,6 +94,13 @@ impl Process {
         Some(address)
     }
 
+    /// Resolves a pointer path like [`Process::deref_offsets`] and reads the
+    /// value at the end of it in one call.
+    pub fn deref_offsets_into<T: Copy>(&self, address: Address, offsets: &[i64]) -> Option<T> {
+        let address = self.deref_offsets(address, offsets)?;
+        self.read_value(address)
+    }
+
     /// Reads a string from the target process' memory space
     pub fn read_string<const N: usize>(
         &self,
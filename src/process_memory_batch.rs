@@ -0,0 +1,110 @@
+use std::mem::{self, MaybeUninit};
+
+use crate::process::Process;
+use crate::process_memory::Address;
+
+/// Gaps between queued reads of at most this many bytes are coalesced into a single
+/// `ReadProcessMemory` call.
+const COALESCE_GAP: usize = 0x1000;
+
+struct QueuedRead {
+    address: Address,
+    ptr: *mut u8,
+    len: usize,
+}
+
+/// Queues up several reads against a [`Process`] and, on [`commit`], issues
+/// one `ReadProcessMemory` call per group of nearby addresses instead of one
+/// per read. Obtain one via [`Process::batch_reads`].
+///
+/// [`commit`]: ReadBatcher::commit
+pub struct ReadBatcher<'a> {
+    process: &'a Process,
+    requests: Vec<QueuedRead>,
+}
+
+impl<'a> ReadBatcher<'a> {
+    /// Queues a read of `buf.len()` values of `T` starting at `address`. The
+    /// read is not performed until [`ReadBatcher::commit`] is called, so
+    /// `buf` is tied to the batcher's lifetime `'a` to prevent it from being
+    /// dropped (e.g. a stack-local array going out of scope) before then.
+    pub fn queue_read<T: Copy>(&mut self, address: Address, buf: &'a mut [MaybeUninit<T>]) -> &mut Self {
+        self.requests.push(QueuedRead {
+            address,
+            ptr: buf.as_mut_ptr() as *mut u8,
+            len: mem::size_of_val(buf),
+        });
+        self
+    }
+
+    /// Executes every queued request. Requests whose addresses are within
+    /// [`COALESCE_GAP`] bytes of each other are coalesced into a single
+    /// `ReadProcessMemory` call against one staging buffer, whose contents
+    /// are then scattered back into each request's destination. If the
+    /// coalesced read fails (e.g. a gap straddles an unmapped page), the
+    /// requests in that group are retried individually so that partial
+    /// success is still reported.
+    ///
+    /// Returns one success flag per request, in the order it was queued.
+    pub fn commit(self) -> Vec<bool> {
+        let mut order: Vec<usize> = (0..self.requests.len()).collect();
+        order.sort_by_key(|&i| self.requests[i].address);
+
+        let mut results = vec![false; self.requests.len()];
+        let mut i = 0;
+        while i < order.len() {
+            let mut j = i + 1;
+            let first = &self.requests[order[i]];
+            let mut group_end = first.address + first.len as isize;
+
+            while j < order.len() {
+                let req = &self.requests[order[j]];
+                if req.address > group_end + COALESCE_GAP as isize {
+                    break;
+                }
+                group_end = group_end.max(req.address + req.len as isize);
+                j += 1;
+            }
+
+            let group_base = first.address;
+            let group_size = (group_end - group_base) as usize;
+            let mut staging = vec![0u8; group_size];
+
+            if self.process.read_buf(group_base, &mut staging) {
+                for &idx in &order[i..j] {
+                    let req = &self.requests[idx];
+                    let offset = (req.address - group_base) as usize;
+                    unsafe {
+                        std::ptr::copy_nonoverlapping(
+                            staging[offset..].as_ptr(),
+                            req.ptr,
+                            req.len,
+                        );
+                    }
+                    results[idx] = true;
+                }
+            } else {
+                for &idx in &order[i..j] {
+                    let req = &self.requests[idx];
+                    let buf = unsafe { std::slice::from_raw_parts_mut(req.ptr, req.len) };
+                    results[idx] = self.process.read_buf(req.address, buf);
+                }
+            }
+
+            i = j;
+        }
+
+        results
+    }
+}
+
+impl Process {
+    /// Starts building a batch of reads to issue together. See
+    /// [`ReadBatcher`].
+    pub fn batch_reads(&self) -> ReadBatcher<'_> {
+        ReadBatcher {
+            process: self,
+            requests: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::mem::{size_of, MaybeUninit};
+
+use windows_sys::Win32::System::Memory::{
+    VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_COMMIT, MEM_FREE, MEM_IMAGE, MEM_MAPPED,
+    MEM_PRIVATE, MEM_RESERVE, PAGE_EXECUTE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE,
+    PAGE_EXECUTE_WRITECOPY, PAGE_READONLY, PAGE_READWRITE, PAGE_WRITECOPY,
+};
+
+use crate::process::Process;
+use crate::process_memory::Address;
+
+impl Process {
+    /// Walks the process' address space with `VirtualQueryEx`, returning an
+    /// iterator over every region found, whether committed, reserved or
+    /// free. This is a natural source of ranges to feed into
+    /// [`Process::scan_pattern`](crate::process_scan).
+    pub fn regions(&self) -> impl Iterator<Item = MemoryRegion> + '_ {
+        RegionIter {
+            process: self,
+            cursor: 0,
+            done: false,
+        }
+    }
+}
+
+struct RegionIter<'a> {
+    process: &'a Process,
+    cursor: Address,
+    done: bool,
+}
+
+impl Iterator for RegionIter<'_> {
+    type Item = MemoryRegion;
+
+    fn next(&mut self) -> Option<MemoryRegion> {
+        if self.done {
+            return None;
+        }
+
+        let mut info = MaybeUninit::<MEMORY_BASIC_INFORMATION>::uninit();
+        let written = unsafe {
+            VirtualQueryEx(
+                self.process.handle,
+                self.cursor as _,
+                info.as_mut_ptr(),
+                size_of::<MEMORY_BASIC_INFORMATION>(),
+            )
+        };
+
+        if written == 0 {
+            self.done = true;
+            return None;
+        }
+
+        let info = unsafe { info.assume_init() };
+        let region = MemoryRegion::from_raw(&info);
+
+        let next_cursor = (info.BaseAddress as Address).wrapping_add(info.RegionSize as Address);
+        if next_cursor <= self.cursor {
+            // The cursor wrapped around the top of the address space.
+            self.done = true;
+        } else {
+            self.cursor = next_cursor;
+        }
+
+        Some(region)
+    }
+}
+
+/// A single region of the target process' virtual address space, as
+/// reported by `VirtualQueryEx`.
+#[derive(Copy, Clone, Debug)]
+pub struct MemoryRegion {
+    pub base: Address,
+    pub size: usize,
+    pub state: RegionState,
+    pub kind: RegionType,
+    pub readable: bool,
+    pub writable: bool,
+    pub executable: bool,
+}
+
+impl MemoryRegion {
+    fn from_raw(info: &MEMORY_BASIC_INFORMATION) -> Self {
+        let (readable, writable, executable) = protection_flags(info.Protect);
+
+        Self {
+            base: info.BaseAddress as Address,
+            size: info.RegionSize,
+            state: RegionState::from_raw(info.State),
+            kind: RegionType::from_raw(info.Type),
+            readable,
+            writable,
+            executable,
+        }
+    }
+}
+
+/// Whether a region is backed by memory, merely reserved, or entirely free.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RegionState {
+    Committed,
+    Reserved,
+    Free,
+}
+
+impl RegionState {
+    fn from_raw(state: u32) -> Self {
+        match state {
+            MEM_COMMIT => Self::Committed,
+            MEM_RESERVE => Self::Reserved,
+            _ => Self::Free,
+        }
+    }
+}
+
+/// The backing of a committed or reserved region.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RegionType {
+    /// Mapped from an executable or DLL image.
+    Image,
+    /// Mapped from a memory-mapped file.
+    Mapped,
+    /// Private memory, e.g. from `VirtualAlloc`.
+    Private,
+    /// The region is free and has no backing.
+    None,
+}
+
+impl RegionType {
+    fn from_raw(kind: u32) -> Self {
+        match kind {
+            MEM_IMAGE => Self::Image,
+            MEM_MAPPED => Self::Mapped,
+            MEM_PRIVATE => Self::Private,
+            _ => Self::None,
+        }
+    }
+}
+
+fn protection_flags(protect: u32) -> (bool, bool, bool) {
+    // PAGE_GUARD/PAGE_NOCACHE/PAGE_WRITECOMBINE are modifier bits layered on
+    // top of a base PAGE_* constant (e.g. guard pages on thread stacks are
+    // PAGE_READWRITE | PAGE_GUARD), so mask them off before classifying.
+    let base = protect & 0xff;
+
+    let readable = matches!(
+        base,
+        PAGE_READONLY
+            | PAGE_READWRITE
+            | PAGE_WRITECOPY
+            | PAGE_EXECUTE_READ
+            | PAGE_EXECUTE_READWRITE
+            | PAGE_EXECUTE_WRITECOPY
+    );
+    let writable = matches!(
+        base,
+        PAGE_READWRITE | PAGE_WRITECOPY | PAGE_EXECUTE_READWRITE | PAGE_EXECUTE_WRITECOPY
+    );
+    let executable = matches!(
+        base,
+        PAGE_EXECUTE | PAGE_EXECUTE_READ | PAGE_EXECUTE_READWRITE | PAGE_EXECUTE_WRITECOPY
+    );
+
+    (readable, writable, executable)
+}
@@ -0,0 +1,134 @@
+use std::mem::{size_of, MaybeUninit};
+use std::time::{Duration, SystemTime};
+
+use windows_sys::Win32::Foundation::FILETIME;
+use windows_sys::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS_EX};
+use windows_sys::Win32::System::Threading::{GetProcessIoCounters, GetProcessTimes, IO_COUNTERS};
+
+use crate::common::{filetime_to_duration, filetime_to_system_time};
+use crate::process::Process;
+
+impl Process {
+    /// Returns the process' current memory usage, via `GetProcessMemoryInfo`.
+    pub fn get_memory_info(&self) -> Option<MemoryInfo> {
+        let mut counters = MaybeUninit::<PROCESS_MEMORY_COUNTERS_EX>::uninit();
+
+        let success = unsafe {
+            GetProcessMemoryInfo(
+                self.handle,
+                counters.as_mut_ptr() as *mut _,
+                size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32,
+            )
+        };
+
+        if success == 0 {
+            return None;
+        }
+
+        let counters = unsafe { counters.assume_init() };
+        Some(MemoryInfo {
+            working_set_size: counters.WorkingSetSize,
+            private_usage: counters.PrivateUsage,
+            page_fault_count: counters.PageFaultCount,
+        })
+    }
+
+    /// Returns the process' creation time and accumulated CPU time, via
+    /// `GetProcessTimes`.
+    pub fn get_cpu_times(&self) -> Option<CpuTimes> {
+        let mut creation = MaybeUninit::<FILETIME>::uninit();
+        let mut exit = MaybeUninit::<FILETIME>::uninit();
+        let mut kernel = MaybeUninit::<FILETIME>::uninit();
+        let mut user = MaybeUninit::<FILETIME>::uninit();
+
+        let success = unsafe {
+            GetProcessTimes(
+                self.handle,
+                creation.as_mut_ptr(),
+                exit.as_mut_ptr(),
+                kernel.as_mut_ptr(),
+                user.as_mut_ptr(),
+            )
+        };
+
+        if success == 0 {
+            return None;
+        }
+
+        let kernel = unsafe { kernel.assume_init() };
+        let user = unsafe { user.assume_init() };
+
+        // Creation time can't change for the life of the process, so reuse
+        // it if a prior call already cached it instead of re-converting it.
+        let start_time = match self.cached_start_time() {
+            Some(start_time) => start_time,
+            None => {
+                let creation = unsafe { creation.assume_init() };
+                let start_time = filetime_to_system_time(creation);
+                self.set_cached_start_time(start_time);
+                start_time
+            }
+        };
+
+        Some(CpuTimes {
+            start_time,
+            kernel_time: filetime_to_duration(kernel),
+            user_time: filetime_to_duration(user),
+        })
+    }
+
+    /// Returns the process' accumulated I/O statistics, via
+    /// `GetProcessIoCounters`.
+    pub fn get_io_counters(&self) -> Option<IoCounters> {
+        let mut counters = MaybeUninit::<IO_COUNTERS>::uninit();
+        let success = unsafe { GetProcessIoCounters(self.handle, counters.as_mut_ptr()) };
+
+        if success == 0 {
+            return None;
+        }
+
+        let counters = unsafe { counters.assume_init() };
+        Some(IoCounters {
+            read_operation_count: counters.ReadOperationCount,
+            write_operation_count: counters.WriteOperationCount,
+            other_operation_count: counters.OtherOperationCount,
+            read_transfer_count: counters.ReadTransferCount,
+            write_transfer_count: counters.WriteTransferCount,
+            other_transfer_count: counters.OtherTransferCount,
+        })
+    }
+}
+
+/// Memory usage counters, as reported by `GetProcessMemoryInfo`.
+#[derive(Copy, Clone, Debug)]
+pub struct MemoryInfo {
+    pub working_set_size: usize,
+    pub private_usage: usize,
+    pub page_fault_count: u32,
+}
+
+/// CPU usage counters, as reported by `GetProcessTimes`.
+#[derive(Copy, Clone, Debug)]
+pub struct CpuTimes {
+    pub start_time: SystemTime,
+    pub kernel_time: Duration,
+    pub user_time: Duration,
+}
+
+impl CpuTimes {
+    /// The total CPU time spent by the process, combining kernel and user time.
+    pub fn total_time(&self) -> Duration {
+        self.kernel_time + self.user_time
+    }
+}
+
+/// I/O usage counters, as reported by `GetProcessIoCounters`.
+#[derive(Copy, Clone, Debug)]
+pub struct IoCounters {
+    pub read_operation_count: u64,
+    pub write_operation_count: u64,
+    pub other_operation_count: u64,
+    pub read_transfer_count: u64,
+    pub write_transfer_count: u64,
+    pub other_transfer_count: u64,
+}
@@ -14,33 +14,44 @@ use crate::process::Process;
 use crate::process_memory::Address;
 
 impl Process {
-    /// Enumerates the modules loaded by the target process
+    /// Enumerates the modules loaded by the target process. The iterator is
+    /// unbounded: a heap buffer is grown and `K32EnumProcessModulesEx`
+    /// retried until it returns fewer handles than the buffer could hold.
     pub fn modules(&self) -> impl DoubleEndedIterator<Item = ProcessModule> + '_ {
-        let mut lphmodule =
-            unsafe { MaybeUninit::<[MaybeUninit<HINSTANCE>; 1024]>::uninit().assume_init() };
-        let mut lpcneeded = MaybeUninit::<u32>::uninit();
-
-        let success = unsafe {
-            K32EnumProcessModulesEx(
-                self.handle,
-                lphmodule.as_mut_ptr() as *mut _,
-                size_of::<HINSTANCE>().saturating_mul(1024) as _,
-                lpcneeded.as_mut_ptr(),
-                0x03,
-            )
-        };
+        const INITIAL_CAPACITY: usize = 1024;
+
+        let mut capacity = INITIAL_CAPACITY;
+        let handles = loop {
+            let mut lphmodule = vec![0 as HINSTANCE; capacity];
+            let mut lpcneeded = 0u32;
+
+            let success = unsafe {
+                K32EnumProcessModulesEx(
+                    self.handle,
+                    lphmodule.as_mut_ptr(),
+                    (lphmodule.len() * size_of::<HINSTANCE>()) as u32,
+                    &mut lpcneeded,
+                    0x03,
+                )
+            };
+
+            if success == 0 {
+                break Vec::new();
+            }
+
+            let returned = lpcneeded as usize / size_of::<HINSTANCE>();
+            if returned < lphmodule.len() {
+                lphmodule.truncate(returned);
+                break lphmodule;
+            }
 
-        let number_of_modules = match success {
-            0 => 0,
-            _ => unsafe {
-                lpcneeded
-                    .assume_init()
-                    .saturating_div(size_of::<HINSTANCE>() as _)
-            },
+            // The buffer was filled exactly: modules may have been
+            // truncated, so grow and retry.
+            capacity *= 2;
         };
 
-        (0..number_of_modules as usize).map(move |i| ProcessModule {
-            module_handle: unsafe { lphmodule[i].assume_init() },
+        handles.into_iter().map(|module_handle| ProcessModule {
+            module_handle,
             name: RefCell::new(None),
             file_name: RefCell::new(None),
             module_info: Cell::new(None),
@@ -0,0 +1,79 @@
+use std::mem::MaybeUninit;
+
+use windows_sys::Win32::Foundation::CloseHandle;
+use windows_sys::Win32::Security::{
+    GetTokenInformation, LookupAccountSidW, OpenProcessToken, SID_NAME_USE, TOKEN_QUERY,
+    TOKEN_USER, TokenUser,
+};
+
+use crate::common::get_string_utf16;
+use crate::process::Process;
+
+impl Process {
+    /// Returns the `domain\user` account that owns the process, resolved
+    /// from the process token's `TokenUser` SID via `LookupAccountSidW`.
+    pub fn get_owner(&self) -> Option<String> {
+        let mut token = 0;
+        if unsafe { OpenProcessToken(self.handle, TOKEN_QUERY, &mut token) } == 0 {
+            return None;
+        }
+
+        let owner = self.owner_from_token(token);
+        unsafe {
+            CloseHandle(token);
+        }
+        owner
+    }
+
+    fn owner_from_token(&self, token: isize) -> Option<String> {
+        let mut required_len = 0u32;
+        unsafe {
+            GetTokenInformation(token, TokenUser, std::ptr::null_mut(), 0, &mut required_len);
+        }
+        if required_len == 0 {
+            return None;
+        }
+
+        let mut buf = vec![0u8; required_len as usize];
+        let success = unsafe {
+            GetTokenInformation(
+                token,
+                TokenUser,
+                buf.as_mut_ptr() as *mut _,
+                required_len,
+                &mut required_len,
+            )
+        };
+        if success == 0 {
+            return None;
+        }
+
+        let token_user = unsafe { &*(buf.as_ptr() as *const TOKEN_USER) };
+        let sid = token_user.User.Sid;
+
+        let mut name = [0u16; 256];
+        let mut name_len = name.len() as u32;
+        let mut domain = [0u16; 256];
+        let mut domain_len = domain.len() as u32;
+        let mut sid_use = MaybeUninit::<SID_NAME_USE>::uninit();
+
+        let success = unsafe {
+            LookupAccountSidW(
+                std::ptr::null(),
+                sid,
+                name.as_mut_ptr(),
+                &mut name_len,
+                domain.as_mut_ptr(),
+                &mut domain_len,
+                sid_use.as_mut_ptr(),
+            )
+        };
+        if success == 0 {
+            return None;
+        }
+
+        let domain = get_string_utf16(&domain[..domain_len as usize])?;
+        let name = get_string_utf16(&name[..name_len as usize])?;
+        Some(format!("{domain}\\{name}"))
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+use crate::process::Process;
+use crate::process_memory::Address;
+use crate::process_module::ProcessModule;
+
+/// Size of the window read from the target process at a time while scanning.
+const CHUNK_SIZE: usize = 4096;
+
+/// An IDA-style byte signature such as `"48 8B ?? ?? C3"`, parsed into a
+/// literal byte vector alongside a mask marking which bytes are wildcards
+/// (`??` or `?`).
+struct Signature {
+    bytes: Vec<u8>,
+    mask: Vec<bool>,
+}
+
+impl Signature {
+    /// Parses an IDA-style pattern into bytes and a wildcard mask. Returns
+    /// `None` if any token is neither `?`/`??` nor a valid 2-digit hex byte,
+    /// rather than silently treating a typo'd token as a literal `0x00`.
+    fn parse(pattern: &str) -> Option<Self> {
+        let mut bytes = Vec::new();
+        let mut mask = Vec::new();
+
+        for token in pattern.split_whitespace() {
+            if token == "?" || token == "??" {
+                bytes.push(0);
+                mask.push(false);
+            } else {
+                if token.len() != 2 || !token.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+                bytes.push(u8::from_str_radix(token, 16).ok()?);
+                mask.push(true);
+            }
+        }
+
+        Some(Self { bytes, mask })
+    }
+
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// The offset and value of the first non-wildcard byte in the pattern,
+    /// used to anchor the search.
+    fn anchor(&self) -> Option<(usize, u8)> {
+        self.mask
+            .iter()
+            .position(|&concrete| concrete)
+            .map(|offset| (offset, self.bytes[offset]))
+    }
+
+    fn matches_at(&self, haystack: &[u8], start: usize) -> bool {
+        self.bytes
+            .iter()
+            .zip(&self.mask)
+            .enumerate()
+            .all(|(i, (&byte, &concrete))| !concrete || haystack[start + i] == byte)
+    }
+
+    /// Returns the start offset of every match found in `haystack`, anchoring
+    /// on the first concrete byte to skip past the bulk of non-matching
+    /// positions without a full masked compare.
+    fn find_all(&self, haystack: &[u8]) -> Vec<usize> {
+        let mut matches = Vec::new();
+
+        let Some((anchor_offset, anchor_byte)) = self.anchor() else {
+            return matches;
+        };
+        if haystack.len() < self.len() {
+            return matches;
+        }
+
+        let last_start = haystack.len() - self.len();
+        let mut search_from = anchor_offset;
+        while let Some(pos) = haystack[search_from..]
+            .iter()
+            .position(|&byte| byte == anchor_byte)
+        {
+            let anchor_pos = search_from + pos;
+            search_from = anchor_pos + 1;
+
+            // Patterns that start with a wildcard anchor on a later byte, so
+            // the match start sits behind the anchor position.
+            let start = anchor_pos - anchor_offset;
+            if start > last_start {
+                break;
+            }
+            if self.matches_at(haystack, start) {
+                matches.push(start);
+            }
+        }
+
+        matches
+    }
+}
+
+impl Process {
+    /// Scans `range` (a `(base, size)` pair) for the first occurrence of
+    /// `pattern`, an IDA-style byte signature such as `"48 8B ?? ?? C3"`, and
+    /// returns the address of its first byte.
+    pub fn scan_pattern(&self, range: (Address, usize), pattern: &str) -> Option<Address> {
+        self.scan_pattern_iter(range, pattern).next()
+    }
+
+    /// Scans `range` for every occurrence of `pattern`, returning an
+    /// iterator of match addresses. See [`Process::scan_pattern`] for the
+    /// pattern syntax.
+    pub fn scan_pattern_iter<'a>(
+        &'a self,
+        range: (Address, usize),
+        pattern: &str,
+    ) -> impl Iterator<Item = Address> + 'a {
+        let (base, size) = range;
+        let signature = Signature::parse(pattern);
+        let overlap = signature.as_ref().map_or(0, |sig| sig.len().saturating_sub(1));
+        // An unparseable pattern yields an iterator that finds nothing,
+        // rather than one that would search for a bogus literal byte.
+        let end = if signature.is_some() { base + size as isize } else { base };
+        ScanIter {
+            process: self,
+            signature: signature.unwrap_or(Signature { bytes: Vec::new(), mask: Vec::new() }),
+            end,
+            cursor: base,
+            reported_until: base,
+            buffer: vec![0u8; CHUNK_SIZE + overlap],
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Scans `module`'s mapped image for the first occurrence of `pattern`.
+    /// See [`Process::scan_pattern`] for the pattern syntax.
+    pub fn scan_signature(&self, module: &ProcessModule, pattern: &str) -> Option<Address> {
+        self.scan_signature_iter(module, pattern).next()
+    }
+
+    /// Scans `module`'s mapped image for every occurrence of `pattern`.
+    /// See [`Process::scan_pattern`] for the pattern syntax.
+    pub fn scan_signature_iter<'a>(
+        &'a self,
+        module: &ProcessModule,
+        pattern: &str,
+    ) -> impl Iterator<Item = Address> + 'a {
+        let base = module.get_base_address(self).unwrap_or(0);
+        let size = module.get_module_size(self).unwrap_or(0) as usize;
+        self.scan_pattern_iter((base, size), pattern)
+    }
+}
+
+struct ScanIter<'a> {
+    process: &'a Process,
+    signature: Signature,
+    end: Address,
+    cursor: Address,
+    /// Addresses below this point have already been matched against a full,
+    /// successfully-read window and must not be reconsidered.
+    reported_until: Address,
+    buffer: Vec<u8>,
+    pending: VecDeque<Address>,
+}
+
+impl Iterator for ScanIter<'_> {
+    type Item = Address;
+
+    fn next(&mut self) -> Option<Address> {
+        loop {
+            if let Some(address) = self.pending.pop_front() {
+                return Some(address);
+            }
+            if self.cursor >= self.end {
+                return None;
+            }
+
+            let siglen = self.signature.len();
+            let overlap = siglen.saturating_sub(1);
+            let remaining = (self.end - self.cursor) as usize;
+            let to_read = remaining.min(CHUNK_SIZE + overlap);
+
+            let slice = &mut self.buffer[..to_read];
+            if self.process.read_buf(self.cursor, slice) {
+                let base = self.cursor;
+                let reported_until = self.reported_until;
+                for offset in self.signature.find_all(slice) {
+                    let address = base + offset as isize;
+                    if address >= reported_until {
+                        self.pending.push_back(address);
+                    }
+                }
+
+                if to_read >= siglen {
+                    self.reported_until = self.reported_until.max(base + (to_read - siglen + 1) as isize);
+                }
+            }
+            // On a failed read, skip this window entirely and keep scanning
+            // forward rather than aborting the whole range.
+
+            let advance = to_read.saturating_sub(overlap).max(1);
+            self.cursor += advance as isize;
+        }
+    }
+}